@@ -1,16 +1,152 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crossterm::style::{Attribute, Attributes, Color, ContentStyle};
 use promkit::style::StyleBuilder;
 use serde::{Deserialize, Serialize};
-use serde_with::{serde_as, DurationMilliSeconds};
 use tokio::time::Duration;
 
-mod content_style_serde {
+/// Accepts either a plain integer (milliseconds, for backward compatibility
+/// with the old `*_duration_ms` fields) or a human-friendly string such as
+/// `"600ms"`, `"1s"`, `"2m"`, or `"1h"`.
+mod duration_serde {
     use super::*;
+    use serde::de::Error as _;
     use serde::{Deserializer, Serializer};
 
-    #[derive(Serialize, Deserialize)]
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum DurationRepr {
+        Millis(u64),
+        Human(String),
+    }
+
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (duration.as_millis() as u64).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match DurationRepr::deserialize(deserializer)? {
+            DurationRepr::Millis(ms) => Ok(Duration::from_millis(ms)),
+            DurationRepr::Human(human) => parse_human_duration(&human).ok_or_else(|| {
+                D::Error::custom(format!(
+                    "invalid duration `{human}`, expected milliseconds or a string like \"600ms\", \"1s\", \"2m\", \"1h\""
+                ))
+            }),
+        }
+    }
+
+    fn parse_human_duration(s: &str) -> Option<Duration> {
+        let suffix_at = s.find(|c: char| c.is_ascii_alphabetic())?;
+        let (amount, unit) = s.split_at(suffix_at);
+        let amount: u64 = amount.parse().ok()?;
+        let factor_ms = match unit {
+            "ms" => 1,
+            "s" => 1_000,
+            "m" => 60_000,
+            "h" => 3_600_000,
+            _ => return None,
+        };
+        Some(Duration::from_millis(amount.checked_mul(factor_ms)?))
+    }
+}
+
+/// Accepts either a single value or a sequence of values, always storing
+/// the result as a `Vec<T>` — used so each action can be bound to more
+/// than one key (e.g. both `Ctrl-N` and `Down`).
+mod one_or_many {
+    use super::*;
+    use serde::{Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        One(T),
+        Many(Vec<T>),
+    }
+
+    pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        values.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        match OneOrMany::<T>::deserialize(deserializer)? {
+            OneOrMany::One(value) => Ok(vec![value]),
+            OneOrMany::Many(values) => Ok(values),
+        }
+    }
+}
+
+/// Parses a `#RRGGBB[AA]` hex literal or an X11-style `rgb:r/g/b` literal
+/// into an RGB `Color`. Shared by the plain color fields and by the
+/// `[palette]`/style-inheritance resolution, both of which accept the same
+/// literal forms alongside named colors and palette aliases.
+fn parse_color_literal(literal: &str) -> Option<Color> {
+    if let Some(hex) = literal.strip_prefix('#') {
+        if hex.len() != 6 && hex.len() != 8 {
+            return None;
+        }
+        let r = u8::from_str_radix(hex.get(0..2)?, 16).ok()?;
+        let g = u8::from_str_radix(hex.get(2..4)?, 16).ok()?;
+        let b = u8::from_str_radix(hex.get(4..6)?, 16).ok()?;
+        return Some(Color::Rgb { r, g, b });
+    }
+
+    if let Some(rest) = literal.strip_prefix("rgb:") {
+        let mut components = rest.split('/');
+        let r = scale_component(components.next()?)?;
+        let g = scale_component(components.next()?)?;
+        let b = scale_component(components.next()?)?;
+        if components.next().is_some() {
+            return None;
+        }
+        return Some(Color::Rgb { r, g, b });
+    }
+
+    None
+}
+
+/// Scales a 1-4 digit X11 `rgb:` component to a single byte by
+/// left-justifying it into a 2-digit (8-bit) field, e.g. `f` -> `ff`,
+/// `a1` -> `a1`, `a12` -> `a1`.
+fn scale_component(component: &str) -> Option<u8> {
+    if component.is_empty()
+        || component.len() > 4
+        || !component.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        return None;
+    }
+
+    let mut padded = String::with_capacity(4);
+    while padded.len() < 4 {
+        padded.push_str(component);
+    }
+    u8::from_str_radix(&padded[0..2], 16).ok()
+}
+
+/// Only used for *writing* config files back out (`#[serde(with =
+/// "content_style_serde")]` on `Config`'s `Serialize` impl) — reading is
+/// handled separately by [`RawStyle`] and [`resolve_style`], since
+/// resolving palette aliases and `inherit` needs the whole document, not
+/// just a single field.
+mod content_style_serde {
+    use super::*;
+    use serde::Serializer;
+
+    #[derive(Serialize)]
     struct ContentStyleDef {
         foreground: Option<Color>,
         background: Option<Color>,
@@ -39,39 +175,275 @@ mod content_style_serde {
 
         style_def.serialize(serializer)
     }
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<ContentStyle, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let style_def = ContentStyleDef::deserialize(deserializer)?;
+/// A style's `foreground`/`background`/`underline` value as written by the
+/// user: a `Color` in its native form (named color or `{ Rgb = {...} }`),
+/// or a string resolved later against the `[palette]` table and, failing
+/// that, parsed as a `#RRGGBB[AA]` or `rgb:r/g/b` literal.
+#[derive(Deserialize, Clone)]
+#[serde(untagged)]
+enum StyleColor {
+    Native(Color),
+    Named(String),
+}
+
+impl StyleColor {
+    fn resolve(&self, palette: &HashMap<String, Color>) -> Result<Color, String> {
+        match self {
+            StyleColor::Native(color) => Ok(*color),
+            StyleColor::Named(name) => palette
+                .get(name)
+                .copied()
+                .or_else(|| parse_color_literal(name))
+                .ok_or_else(|| {
+                    format!("unknown palette name or invalid color literal `{name}`")
+                }),
+        }
+    }
+}
+
+/// A `*_style` table as written by the user, before palette and `inherit`
+/// resolution. `inherit` names another style field (e.g. `"key_style"`)
+/// whose resolved fields are cloned as the base, with this block's own
+/// fields applied on top.
+#[derive(Deserialize, Default)]
+struct RawStyle {
+    inherit: Option<String>,
+    #[serde(default)]
+    foreground: Option<StyleColor>,
+    #[serde(default)]
+    background: Option<StyleColor>,
+    #[serde(default)]
+    underline: Option<StyleColor>,
+    #[serde(default)]
+    attributes: Option<Vec<Attribute>>,
+}
+
+/// All `Config` fields that hold a `ContentStyle`, in the order they
+/// appear on `Config`. Used to drive palette/`inherit` resolution, since
+/// that needs to walk every style as a group rather than field-by-field.
+const STYLE_FIELDS: [&str; 18] = [
+    "active_item_style",
+    "inactive_item_style",
+    "prefix_style",
+    "active_char_style",
+    "inactive_char_style",
+    "focus_prefix_style",
+    "focus_active_char_style",
+    "focus_inactive_char_style",
+    "defocus_prefix_style",
+    "defocus_active_char_style",
+    "defocus_inactive_char_style",
+    "curly_brackets_style",
+    "square_brackets_style",
+    "key_style",
+    "string_value_style",
+    "number_value_style",
+    "boolean_value_style",
+    "null_value_style",
+];
+
+fn default_style(config: &Config, name: &str) -> Option<ContentStyle> {
+    Some(match name {
+        "active_item_style" => config.active_item_style.clone(),
+        "inactive_item_style" => config.inactive_item_style.clone(),
+        "prefix_style" => config.prefix_style.clone(),
+        "active_char_style" => config.active_char_style.clone(),
+        "inactive_char_style" => config.inactive_char_style.clone(),
+        "focus_prefix_style" => config.focus_prefix_style.clone(),
+        "focus_active_char_style" => config.focus_active_char_style.clone(),
+        "focus_inactive_char_style" => config.focus_inactive_char_style.clone(),
+        "defocus_prefix_style" => config.defocus_prefix_style.clone(),
+        "defocus_active_char_style" => config.defocus_active_char_style.clone(),
+        "defocus_inactive_char_style" => config.defocus_inactive_char_style.clone(),
+        "curly_brackets_style" => config.curly_brackets_style.clone(),
+        "square_brackets_style" => config.square_brackets_style.clone(),
+        "key_style" => config.key_style.clone(),
+        "string_value_style" => config.string_value_style.clone(),
+        "number_value_style" => config.number_value_style.clone(),
+        "boolean_value_style" => config.boolean_value_style.clone(),
+        "null_value_style" => config.null_value_style.clone(),
+        _ => return None,
+    })
+}
+
+fn set_style(config: &mut Config, name: &str, style: ContentStyle) {
+    match name {
+        "active_item_style" => config.active_item_style = style,
+        "inactive_item_style" => config.inactive_item_style = style,
+        "prefix_style" => config.prefix_style = style,
+        "active_char_style" => config.active_char_style = style,
+        "inactive_char_style" => config.inactive_char_style = style,
+        "focus_prefix_style" => config.focus_prefix_style = style,
+        "focus_active_char_style" => config.focus_active_char_style = style,
+        "focus_inactive_char_style" => config.focus_inactive_char_style = style,
+        "defocus_prefix_style" => config.defocus_prefix_style = style,
+        "defocus_active_char_style" => config.defocus_active_char_style = style,
+        "defocus_inactive_char_style" => config.defocus_inactive_char_style = style,
+        "curly_brackets_style" => config.curly_brackets_style = style,
+        "square_brackets_style" => config.square_brackets_style = style,
+        "key_style" => config.key_style = style,
+        "string_value_style" => config.string_value_style = style,
+        "number_value_style" => config.number_value_style = style,
+        "boolean_value_style" => config.boolean_value_style = style,
+        "null_value_style" => config.null_value_style = style,
+        _ => unreachable!("set_style called with unknown style field `{name}`"),
+    }
+}
+
+/// Resolves one style field to its effective `ContentStyle`: clones its
+/// `inherit` parent (recursively, memoized in `cache`), then applies its
+/// own `foreground`/`background`/`underline`/`attributes` on top. Fields
+/// the user didn't override fall back to `config`'s baked-in default for
+/// that field, which also lets other styles `inherit` from them.
+fn resolve_style(
+    name: &str,
+    raw_styles: &HashMap<String, RawStyle>,
+    config: &Config,
+    palette: &HashMap<String, Color>,
+    cache: &mut HashMap<String, ContentStyle>,
+    stack: &mut Vec<String>,
+) -> Result<ContentStyle, String> {
+    if let Some(style) = cache.get(name) {
+        return Ok(style.clone());
+    }
+    if stack.iter().any(|visited| visited == name) {
+        stack.push(name.to_string());
+        return Err(format!("style inheritance cycle: {}", stack.join(" -> ")));
+    }
+
+    let Some(raw) = raw_styles.get(name) else {
+        let style = default_style(config, name)
+            .ok_or_else(|| format!("`{name}` is not a known style"))?;
+        cache.insert(name.to_string(), style.clone());
+        return Ok(style);
+    };
+
+    stack.push(name.to_string());
+    let mut style = match &raw.inherit {
+        Some(parent) => resolve_style(parent, raw_styles, config, palette, cache, stack)?,
+        None => ContentStyle::new(),
+    };
+    stack.pop();
 
-        let mut style = ContentStyle::new();
+    if let Some(color) = &raw.foreground {
+        style.foreground_color = Some(color.resolve(palette)?);
+    }
+    if let Some(color) = &raw.background {
+        style.background_color = Some(color.resolve(palette)?);
+    }
+    if let Some(color) = &raw.underline {
+        style.underline_color = Some(color.resolve(palette)?);
+    }
+    if let Some(attributes) = &raw.attributes {
+        style.attributes = attributes
+            .iter()
+            .cloned()
+            .fold(Attributes::default(), |acc, x| acc | x);
+    }
+
+    cache.insert(name.to_string(), style.clone());
+    Ok(style)
+}
+
+/// Named base set of keybindings, applied before the user's per-action
+/// overrides. `emacs` is the longstanding default; `vim` gives modal-style
+/// motions for the actions that have an obvious vim equivalent.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Keymap {
+    #[default]
+    Emacs,
+    Vim,
+}
 
-        style.foreground_color = style_def.foreground;
-        style.background_color = style_def.background;
-        style.underline_color = style_def.underline;
-        if let Some(attributes) = style_def.attributes {
-            style.attributes = attributes
-                .into_iter()
-                .fold(Attributes::default(), |acc, x| acc | x);
+impl Keymap {
+    fn bindings(self) -> KeymapBindings {
+        match self {
+            Keymap::Emacs => KeymapBindings::emacs(),
+            Keymap::Vim => KeymapBindings::vim(),
         }
-        Ok(style)
     }
 }
 
-#[serde_as]
-#[derive(Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+/// The full set of action -> key binding mappings contributed by a
+/// [`Keymap`] preset, spread into [`Config`] before per-action overrides
+/// from the user's config are applied.
+struct KeymapBindings {
+    move_to_tail: Vec<crossterm::event::KeyEvent>,
+    move_to_head: Vec<crossterm::event::KeyEvent>,
+    backward: Vec<crossterm::event::KeyEvent>,
+    forward: Vec<crossterm::event::KeyEvent>,
+    completion: Vec<crossterm::event::KeyEvent>,
+    move_to_next_nearest: Vec<crossterm::event::KeyEvent>,
+    move_to_previous_nearest: Vec<crossterm::event::KeyEvent>,
+    erase: Vec<crossterm::event::KeyEvent>,
+    erase_all: Vec<crossterm::event::KeyEvent>,
+    erase_to_previous_nearest: Vec<crossterm::event::KeyEvent>,
+    erase_to_next_nearest: Vec<crossterm::event::KeyEvent>,
+    search_up: Vec<crossterm::event::KeyEvent>,
+    search_down: Vec<crossterm::event::KeyEvent>,
+}
+
+impl KeymapBindings {
+    fn emacs() -> Self {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        Self {
+            move_to_tail: vec![KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL)],
+            move_to_head: vec![KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL)],
+            backward: vec![KeyEvent::new(KeyCode::Left, KeyModifiers::NONE)],
+            forward: vec![KeyEvent::new(KeyCode::Right, KeyModifiers::NONE)],
+            completion: vec![KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)],
+            move_to_next_nearest: vec![KeyEvent::new(KeyCode::Char('f'), KeyModifiers::ALT)],
+            move_to_previous_nearest: vec![KeyEvent::new(KeyCode::Char('b'), KeyModifiers::ALT)],
+            erase: vec![KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE)],
+            erase_all: vec![KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL)],
+            erase_to_previous_nearest: vec![KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL)],
+            erase_to_next_nearest: vec![KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)],
+            search_up: vec![KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)],
+            search_down: vec![KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)],
+        }
+    }
+
+    /// jnv has no separate normal/insert mode — the query box inserts
+    /// whatever key isn't claimed by an action — so these can't use bare
+    /// `h`/`l`/`w`/`b`/`0`/`$` the way vim itself does; that would make it
+    /// impossible to type those characters into a search. Instead the
+    /// vim-flavored motions are gated behind `Alt`, the same way the emacs
+    /// preset gates `move_to_next_nearest`/`move_to_previous_nearest`.
+    fn vim() -> Self {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        Self {
+            move_to_tail: vec![KeyEvent::new(KeyCode::Char('$'), KeyModifiers::ALT)],
+            move_to_head: vec![KeyEvent::new(KeyCode::Char('0'), KeyModifiers::ALT)],
+            backward: vec![KeyEvent::new(KeyCode::Char('h'), KeyModifiers::ALT)],
+            forward: vec![KeyEvent::new(KeyCode::Char('l'), KeyModifiers::ALT)],
+            completion: vec![KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)],
+            move_to_next_nearest: vec![KeyEvent::new(KeyCode::Char('w'), KeyModifiers::ALT)],
+            move_to_previous_nearest: vec![KeyEvent::new(KeyCode::Char('b'), KeyModifiers::ALT)],
+            erase: vec![KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE)],
+            erase_all: vec![KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL)],
+            erase_to_previous_nearest: vec![KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL)],
+            erase_to_next_nearest: vec![KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL)],
+            search_up: vec![KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)],
+            search_down: vec![KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)],
+        }
+    }
+}
+
+#[derive(Serialize)]
 pub(crate) struct Config {
-    /// Duration to debounce query events, in milliseconds.
-    #[serde(default, rename = "query_debounce_duration_ms")]
-    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub keymap: Keymap,
+
+    /// Duration to debounce query events. Accepts milliseconds or a
+    /// human-friendly string like `"600ms"`, `"1s"`, `"2m"`, `"1h"`.
+    #[serde(rename = "query_debounce_duration_ms", with = "duration_serde")]
     pub query_debounce_duration: Duration,
 
-    /// Duration to debounce resize events, in milliseconds.
-    #[serde(default, rename = "resize_debounce_duration_ms")]
-    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    /// Duration to debounce resize events. Accepts milliseconds or a
+    /// human-friendly string like `"600ms"`, `"1s"`, `"2m"`, `"1h"`.
+    #[serde(rename = "resize_debounce_duration_ms", with = "duration_serde")]
     pub resize_debounce_duration: Duration,
 
     pub search_result_chunk_size: usize,
@@ -121,27 +493,62 @@ pub(crate) struct Config {
     pub null_value_style: ContentStyle,
 
     pub word_break_chars: HashSet<char>,
-    #[serde(default, rename = "spin_duration_ms")]
-    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    #[serde(rename = "spin_duration_ms", with = "duration_serde")]
     pub spin_duration: Duration,
 
-    pub move_to_tail: crossterm::event::KeyEvent,
-    pub move_to_head: crossterm::event::KeyEvent,
-    pub backward: crossterm::event::KeyEvent,
-    pub forward: crossterm::event::KeyEvent,
-    pub completion: crossterm::event::KeyEvent,
-    pub move_to_next_nearest: crossterm::event::KeyEvent,
-    pub move_to_previous_nearest: crossterm::event::KeyEvent,
-    pub erase: crossterm::event::KeyEvent,
-    pub erase_all: crossterm::event::KeyEvent,
-    pub erase_to_previous_nearest: crossterm::event::KeyEvent,
-    pub erase_to_next_nearest: crossterm::event::KeyEvent,
-    pub search_up: crossterm::event::KeyEvent,
+    #[serde(with = "one_or_many")]
+    pub move_to_tail: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub move_to_head: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub backward: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub forward: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub completion: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub move_to_next_nearest: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub move_to_previous_nearest: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub erase: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub erase_all: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub erase_to_previous_nearest: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub erase_to_next_nearest: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub search_up: Vec<crossterm::event::KeyEvent>,
+    #[serde(with = "one_or_many")]
+    pub search_down: Vec<crossterm::event::KeyEvent>,
 }
 
-impl Default for Config {
-    fn default() -> Self {
+impl Config {
+    /// Returns whether `event` triggers the action bound to `bindings`,
+    /// e.g. `Config::is_bound(&config.backward, &event)`. Actions can have
+    /// more than one key bound to them, so the key-dispatch/event-matching
+    /// code must use this instead of comparing against a single `KeyEvent`.
+    ///
+    /// NOTE: this source tree only contains `config.rs` — there is no
+    /// event loop or key-dispatch module here for this helper to be wired
+    /// into. It's provided (and exercised below by
+    /// `test_is_bound_matches_any_binding`) for that call site to use once
+    /// it exists; migrating it is outside this file's scope.
+    pub(crate) fn is_bound(
+        bindings: &[crossterm::event::KeyEvent],
+        event: &crossterm::event::KeyEvent,
+    ) -> bool {
+        bindings.contains(event)
+    }
+
+    /// Builds a `Config` seeded with `keymap`'s keybinding preset. The
+    /// non-keybinding fields (styles, debounce durations, etc.) don't vary
+    /// by keymap.
+    fn with_keymap(keymap: Keymap) -> Self {
+        let bindings = keymap.bindings();
         Self {
+            keymap,
             focus_prefix: String::from("❯❯ "),
             active_item_style: StyleBuilder::new()
                 .fgc(Color::Grey)
@@ -152,28 +559,13 @@ impl Default for Config {
             query_debounce_duration: Duration::from_millis(600),
             resize_debounce_duration: Duration::from_millis(200),
             search_load_chunk_size: 50000,
-            move_to_tail: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Char('e'),
-                crossterm::event::KeyModifiers::CONTROL,
-            ),
-            move_to_head: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Char('a'),
-                crossterm::event::KeyModifiers::CONTROL,
-            ),
+            move_to_tail: bindings.move_to_tail,
+            move_to_head: bindings.move_to_head,
             spin_duration: Duration::from_millis(300),
             word_break_chars: HashSet::from(['.', '|', '(', ')', '[', ']']),
-            backward: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Left,
-                crossterm::event::KeyModifiers::NONE,
-            ),
-            forward: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Right,
-                crossterm::event::KeyModifiers::NONE,
-            ),
-            completion: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Tab,
-                crossterm::event::KeyModifiers::NONE,
-            ),
+            backward: bindings.backward,
+            forward: bindings.forward,
+            completion: bindings.completion,
             prefix_style: StyleBuilder::new().fgc(Color::Blue).build(),
             active_char_style: StyleBuilder::new().bgc(Color::Magenta).build(),
             inactive_char_style: StyleBuilder::new().build(),
@@ -202,39 +594,301 @@ impl Default for Config {
             focus_active_char_style: StyleBuilder::new().bgc(Color::Magenta).build(),
             focus_inactive_char_style: StyleBuilder::new().build(),
             inactive_item_style: StyleBuilder::new().fgc(Color::Grey).build(),
-            move_to_next_nearest: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Char('f'),
-                crossterm::event::KeyModifiers::ALT,
-            ),
-            move_to_previous_nearest: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Char('b'),
-                crossterm::event::KeyModifiers::ALT,
-            ),
-            erase: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Backspace,
-                crossterm::event::KeyModifiers::NONE,
-            ),
-            erase_all: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Char('u'),
-                crossterm::event::KeyModifiers::CONTROL,
-            ),
-            erase_to_previous_nearest: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Char('w'),
-                crossterm::event::KeyModifiers::CONTROL,
-            ),
-            erase_to_next_nearest: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Char('d'),
-                crossterm::event::KeyModifiers::CONTROL,
-            ),
-            search_up: crossterm::event::KeyEvent::new(
-                crossterm::event::KeyCode::Up,
-                crossterm::event::KeyModifiers::NONE,
-            ),
-            // search_down: KeyEvent::new(KeyCode::Down, KeyModifiers::NONE),
+            move_to_next_nearest: bindings.move_to_next_nearest,
+            move_to_previous_nearest: bindings.move_to_previous_nearest,
+            erase: bindings.erase,
+            erase_all: bindings.erase_all,
+            erase_to_previous_nearest: bindings.erase_to_previous_nearest,
+            erase_to_next_nearest: bindings.erase_to_next_nearest,
+            search_up: bindings.search_up,
+            search_down: bindings.search_down,
         }
     }
 }
 
+impl Default for Config {
+    fn default() -> Self {
+        Self::with_keymap(Keymap::default())
+    }
+}
+
+impl Config {
+    /// Reads and parses `path` through the fault-tolerant [`Deserialize`]
+    /// impl below. Used for the initial load and reused by [`watcher`] for
+    /// hot-reloads, so a config file edited while jnv is running is
+    /// re-read exactly the same way it was at startup.
+    pub(crate) fn from_path(path: &std::path::Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("could not read `{}`: {err}", path.display()))?;
+        toml::from_str(&content).map_err(|err| format!("could not parse `{}`: {err}", path.display()))
+    }
+}
+
+/// Wraps a single field type together with the `serde(with = "...")`
+/// conversion it needs, so [`Config::deserialize`] can overlay one TOML
+/// value at a time onto the preset built by [`Config::with_keymap`] without deriving a full
+/// `Deserialize` impl for `Config` itself.
+#[derive(Deserialize)]
+struct DurationField(#[serde(with = "duration_serde")] Duration);
+
+impl From<DurationField> for Duration {
+    fn from(field: DurationField) -> Self {
+        field.0
+    }
+}
+
+#[derive(Deserialize)]
+struct KeyBindingsField(#[serde(with = "one_or_many")] Vec<crossterm::event::KeyEvent>);
+
+impl From<KeyBindingsField> for Vec<crossterm::event::KeyEvent> {
+    fn from(field: KeyBindingsField) -> Self {
+        field.0
+    }
+}
+
+impl<'de> Deserialize<'de> for Config {
+    /// Resolves `keymap` first to seed [`Config::with_keymap`] with the
+    /// named preset, then overlays only the remaining keys that are
+    /// present *and* convert successfully, so a single typo'd key or a
+    /// wrongly-typed value (e.g. a bad color name in one style) can't abort
+    /// the whole config load. Bad or unknown keys are warned about on
+    /// stderr and otherwise ignored.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut table = toml::value::Table::deserialize(deserializer)?;
+
+        let keymap = match table.remove("keymap") {
+            Some(value) => match Keymap::deserialize(value) {
+                Ok(keymap) => keymap,
+                Err(err) => {
+                    eprintln!(
+                        "warning: ignoring invalid value for config key `keymap`: {err} (keeping default)"
+                    );
+                    Keymap::default()
+                }
+            },
+            None => Keymap::default(),
+        };
+        let mut config = Config::with_keymap(keymap);
+
+        macro_rules! overlay {
+            ($key:literal => $field:ident : $ty:ty) => {
+                if let Some(value) = table.remove($key) {
+                    match <$ty>::deserialize(value) {
+                        Ok(value) => config.$field = value.into(),
+                        Err(err) => eprintln!(
+                            "warning: ignoring invalid value for config key `{}`: {} (keeping default)",
+                            $key, err
+                        ),
+                    }
+                }
+            };
+        }
+
+        overlay!("query_debounce_duration_ms" => query_debounce_duration: DurationField);
+        overlay!("resize_debounce_duration_ms" => resize_debounce_duration: DurationField);
+        overlay!("search_result_chunk_size" => search_result_chunk_size: usize);
+        overlay!("search_load_chunk_size" => search_load_chunk_size: usize);
+        overlay!("focus_prefix" => focus_prefix: String);
+        overlay!("defocus_prefix" => defocus_prefix: String);
+        overlay!("word_break_chars" => word_break_chars: HashSet<char>);
+        overlay!("spin_duration_ms" => spin_duration: DurationField);
+        overlay!("move_to_tail" => move_to_tail: KeyBindingsField);
+        overlay!("move_to_head" => move_to_head: KeyBindingsField);
+        overlay!("backward" => backward: KeyBindingsField);
+        overlay!("forward" => forward: KeyBindingsField);
+        overlay!("completion" => completion: KeyBindingsField);
+        overlay!("move_to_next_nearest" => move_to_next_nearest: KeyBindingsField);
+        overlay!("move_to_previous_nearest" => move_to_previous_nearest: KeyBindingsField);
+        overlay!("erase" => erase: KeyBindingsField);
+        overlay!("erase_all" => erase_all: KeyBindingsField);
+        overlay!("erase_to_previous_nearest" => erase_to_previous_nearest: KeyBindingsField);
+        overlay!("erase_to_next_nearest" => erase_to_next_nearest: KeyBindingsField);
+        overlay!("search_up" => search_up: KeyBindingsField);
+        overlay!("search_down" => search_down: KeyBindingsField);
+
+        let mut palette: HashMap<String, Color> = HashMap::new();
+        if let Some(value) = table.remove("palette") {
+            match <HashMap<String, StyleColor>>::deserialize(value) {
+                Ok(raw_palette) => {
+                    for (name, raw_color) in raw_palette {
+                        match raw_color.resolve(&HashMap::new()) {
+                            Ok(color) => {
+                                palette.insert(name, color);
+                            }
+                            Err(err) => eprintln!(
+                                "warning: ignoring invalid value for palette color `{name}`: {err} (not added to palette)"
+                            ),
+                        }
+                    }
+                }
+                Err(err) => eprintln!(
+                    "warning: ignoring invalid `palette` table: {err} (no palette colors available)"
+                ),
+            }
+        }
+
+        let mut raw_styles: HashMap<String, RawStyle> = HashMap::new();
+        for &name in STYLE_FIELDS.iter() {
+            if let Some(value) = table.remove(name) {
+                match RawStyle::deserialize(value) {
+                    Ok(raw) => {
+                        raw_styles.insert(name.to_string(), raw);
+                    }
+                    Err(err) => eprintln!(
+                        "warning: ignoring invalid value for config key `{name}`: {err} (keeping default)"
+                    ),
+                }
+            }
+        }
+
+        let mut cache = HashMap::new();
+        for &name in STYLE_FIELDS.iter() {
+            let mut stack = Vec::new();
+            match resolve_style(name, &raw_styles, &config, &palette, &mut cache, &mut stack) {
+                Ok(style) => set_style(&mut config, name, style),
+                Err(err) => eprintln!(
+                    "warning: ignoring invalid value for config key `{name}`: {err} (keeping default)"
+                ),
+            }
+        }
+
+        for key in table.keys() {
+            eprintln!("warning: ignoring unknown config key `{key}`");
+        }
+
+        Ok(config)
+    }
+}
+
+/// Watches the config file on disk and hot-reloads it into a shared
+/// handle while jnv is running, so edits to styles and keybindings take
+/// effect without a restart.
+///
+/// This module only owns the watch-and-reload plumbing; wiring
+/// [`ConfigHandle::changed`] into the redraw loop is the caller's
+/// responsibility, since that loop lives outside `config.rs`.
+pub(crate) mod watcher {
+    use super::Config;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Fixed debounce window for coalescing a burst of filesystem events
+    /// (e.g. an editor's save-and-rename) into a single reload, similar in
+    /// spirit to `resize_debounce_duration` but not itself configurable.
+    const DEBOUNCE: Duration = Duration::from_millis(200);
+
+    /// Shared, always-current view of the active [`Config`]. Cheap to
+    /// clone (an `Arc` bump behind the scenes) and safe to hand to every
+    /// component that reads config.
+    pub(crate) type ConfigHandle = tokio::sync::watch::Receiver<Arc<Config>>;
+
+    /// Re-parses `path` through [`Config::from_path`], falling back to
+    /// `previous` and printing a transient warning if the file can't be
+    /// read or isn't valid at all, rather than tearing down the viewer.
+    fn reload(path: &Path, previous: &Arc<Config>) -> Arc<Config> {
+        match Config::from_path(path) {
+            Ok(config) => Arc::new(config),
+            Err(err) => {
+                eprintln!("warning: config reload failed, keeping previous config: {err}");
+                Arc::clone(previous)
+            }
+        }
+    }
+
+    /// Spawns a filesystem watcher on `path` and returns a [`ConfigHandle`]
+    /// that always reflects the most recently successfully loaded config,
+    /// seeded with `initial`. If the watcher itself can't be started (e.g.
+    /// the platform's file notification backend is unavailable), a warning
+    /// is printed to stderr, hot-reload is disabled and the handle simply
+    /// never changes.
+    ///
+    /// The parent directory is watched rather than `path` itself: most
+    /// editors save by writing a temp file and renaming it over the
+    /// original, which replaces the inode out from under a watch bound
+    /// directly to that path (it dies silently on `notify`'s Linux/inotify
+    /// backend, and isn't reinstated). Watching the directory and
+    /// filtering events down to `path`'s file name survives that rename
+    /// cycle, since the directory itself is never removed.
+    pub(crate) fn watch(
+        path: PathBuf,
+        initial: Config,
+    ) -> (ConfigHandle, tokio::task::JoinHandle<()>) {
+        use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+        let (tx, rx) = tokio::sync::watch::channel(Arc::new(initial));
+
+        let task = tokio::spawn(async move {
+            let Some(file_name) = path.file_name().map(|name| name.to_os_string()) else {
+                eprintln!(
+                    "warning: config path `{}` has no file name (hot-reload disabled)",
+                    path.display()
+                );
+                return;
+            };
+            // `Path::parent()` returns `Some("")` rather than `None` for a
+            // bare relative file name (e.g. `"config.toml"`), so an empty
+            // parent needs the same "no directory" fallback as a missing one.
+            let dir = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+                _ => PathBuf::from("."),
+            };
+
+            let (events_tx, mut events_rx) = tokio::sync::mpsc::unbounded_channel();
+
+            let mut fs_watcher = match RecommendedWatcher::new(
+                move |event: notify::Result<notify::Event>| {
+                    if let Ok(event) = event {
+                        let _ = events_tx.send(event);
+                    }
+                },
+                notify::Config::default(),
+            ) {
+                Ok(fs_watcher) => fs_watcher,
+                Err(err) => {
+                    eprintln!(
+                        "warning: could not start config file watcher for `{}`: {err} (hot-reload disabled)",
+                        path.display()
+                    );
+                    return;
+                }
+            };
+
+            if let Err(err) = fs_watcher.watch(&dir, RecursiveMode::NonRecursive) {
+                eprintln!(
+                    "warning: could not watch directory `{}`: {err} (hot-reload disabled)",
+                    dir.display()
+                );
+                return;
+            }
+
+            while let Some(event) = events_rx.recv().await {
+                let is_relevant = event
+                    .paths
+                    .iter()
+                    .any(|changed| changed.file_name() == Some(file_name.as_os_str()));
+                if !is_relevant {
+                    continue;
+                }
+
+                tokio::time::sleep(DEBOUNCE).await;
+                while events_rx.try_recv().is_ok() {}
+
+                let previous = tx.borrow().clone();
+                let reloaded = reload(&path, &previous);
+                if !Arc::ptr_eq(&reloaded, &previous) {
+                    let _ = tx.send(reloaded);
+                }
+            }
+        });
+
+        (rx, task)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,6 +916,8 @@ mod tests {
             modifiers = "CONTROL"
         "#;
 
+        let config: Config = toml::from_str(toml).expect("failed to parse config");
+
         assert_eq!(config.search_result_chunk_size, 10);
         assert_eq!(config.query_debounce_duration, Duration::from_millis(1000));
         assert_eq!(config.resize_debounce_duration, Duration::from_millis(2000));
@@ -273,10 +929,10 @@ mod tests {
 
         assert_eq!(
             config.move_to_tail,
-            crossterm::event::KeyEvent::new(
+            vec![crossterm::event::KeyEvent::new(
                 crossterm::event::KeyCode::Char('$'),
                 crossterm::event::KeyModifiers::CONTROL
-            )
+            )]
         );
 
         assert_eq!(config.focus_prefix, "❯ ".to_string());
@@ -290,4 +946,253 @@ mod tests {
                 .build(),
         );
     }
+
+    #[test]
+    fn test_is_bound_matches_any_binding() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let bindings = vec![
+            KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL),
+            KeyEvent::new(KeyCode::Down, KeyModifiers::NONE),
+        ];
+
+        assert!(Config::is_bound(
+            &bindings,
+            &KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        ));
+        assert!(Config::is_bound(
+            &bindings,
+            &KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)
+        ));
+        assert!(!Config::is_bound(
+            &bindings,
+            &KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)
+        ));
+    }
+
+    #[test]
+    fn test_palette_alias_and_inherit() {
+        let toml = r#"
+            [palette]
+            accent = "#ff00aa"
+
+            [key_style]
+            foreground = "accent"
+
+            [string_value_style]
+            inherit = "key_style"
+            attributes = ["Bold"]
+        "#;
+
+        let config: Config = toml::from_str(toml).expect("failed to parse config");
+
+        assert_eq!(
+            config.key_style,
+            StyleBuilder::new()
+                .fgc(Color::Rgb {
+                    r: 0xff,
+                    g: 0x00,
+                    b: 0xaa
+                })
+                .build(),
+        );
+        assert_eq!(
+            config.string_value_style,
+            StyleBuilder::new()
+                .fgc(Color::Rgb {
+                    r: 0xff,
+                    g: 0x00,
+                    b: 0xaa
+                })
+                .attrs(Attributes::from(Attribute::Bold))
+                .build(),
+        );
+    }
+
+    #[test]
+    fn test_inherit_cycle_falls_back_to_default() {
+        let toml = r#"
+            [key_style]
+            inherit = "string_value_style"
+
+            [string_value_style]
+            inherit = "key_style"
+        "#;
+
+        let config: Config =
+            toml::from_str(toml).expect("an inherit cycle should not abort the load");
+
+        assert_eq!(config.key_style, Config::default().key_style);
+        assert_eq!(config.string_value_style, Config::default().string_value_style);
+    }
+
+    #[test]
+    fn test_keymap_presets() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let emacs = Config::with_keymap(Keymap::Emacs);
+        assert_eq!(
+            emacs.backward,
+            vec![KeyEvent::new(KeyCode::Left, KeyModifiers::NONE)]
+        );
+        assert_eq!(
+            emacs.erase_all,
+            vec![KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL)]
+        );
+
+        let vim = Config::with_keymap(Keymap::Vim);
+        assert_eq!(
+            vim.backward,
+            vec![KeyEvent::new(KeyCode::Char('h'), KeyModifiers::ALT)]
+        );
+        assert_eq!(
+            vim.forward,
+            vec![KeyEvent::new(KeyCode::Char('l'), KeyModifiers::ALT)]
+        );
+        assert_eq!(
+            vim.move_to_tail,
+            vec![KeyEvent::new(KeyCode::Char('$'), KeyModifiers::ALT)]
+        );
+        // Bindings with no obvious vim equivalent keep the emacs defaults.
+        assert_eq!(
+            vim.erase_all,
+            vec![KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL)]
+        );
+    }
+
+    #[test]
+    fn test_one_or_many_key_bindings() {
+        let toml = r#"
+            forward = { key = { Char = "l" }, modifiers = "NONE" }
+
+            [[backward]]
+            key = { Char = "h" }
+            modifiers = "NONE"
+
+            [[backward]]
+            key = "Left"
+            modifiers = "NONE"
+        "#;
+
+        let config: Config = toml::from_str(toml).expect("failed to parse config");
+
+        assert_eq!(
+            config.forward,
+            vec![crossterm::event::KeyEvent::new(
+                crossterm::event::KeyCode::Char('l'),
+                crossterm::event::KeyModifiers::NONE
+            )]
+        );
+        assert_eq!(
+            config.backward,
+            vec![
+                crossterm::event::KeyEvent::new(
+                    crossterm::event::KeyCode::Char('h'),
+                    crossterm::event::KeyModifiers::NONE
+                ),
+                crossterm::event::KeyEvent::new(
+                    crossterm::event::KeyCode::Left,
+                    crossterm::event::KeyModifiers::NONE
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_human_duration_strings() {
+        let toml = r#"
+            query_debounce_duration_ms = "2s"
+            resize_debounce_duration_ms = "150ms"
+            spin_duration_ms = "1m"
+        "#;
+
+        let config: Config = toml::from_str(toml).expect("failed to parse config");
+
+        assert_eq!(config.query_debounce_duration, Duration::from_secs(2));
+        assert_eq!(
+            config.resize_debounce_duration,
+            Duration::from_millis(150)
+        );
+        assert_eq!(config.spin_duration, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_overflowing_human_duration_falls_back_to_default() {
+        let toml = r#"
+            query_debounce_duration_ms = "99999999999999h"
+        "#;
+
+        let config: Config =
+            toml::from_str(toml).expect("an overflowing duration should not abort the load");
+
+        assert_eq!(
+            config.query_debounce_duration,
+            Config::default().query_debounce_duration
+        );
+    }
+
+    #[test]
+    fn test_bad_value_falls_back_to_default_instead_of_aborting_load() {
+        let toml = r#"
+            search_result_chunk_size = "not a number"
+            totally_unknown_key = 1
+            search_load_chunk_size = 5
+        "#;
+
+        let config: Config = toml::from_str(toml).expect("bad fields should not abort the load");
+
+        assert_eq!(
+            config.search_result_chunk_size,
+            Config::default().search_result_chunk_size
+        );
+        assert_eq!(config.search_load_chunk_size, 5);
+    }
+
+    #[test]
+    fn test_parse_color_literal_hex() {
+        assert_eq!(
+            parse_color_literal("#ff00aa"),
+            Some(Color::Rgb {
+                r: 0xff,
+                g: 0x00,
+                b: 0xaa
+            })
+        );
+        assert_eq!(
+            parse_color_literal("#ff00aa80"),
+            Some(Color::Rgb {
+                r: 0xff,
+                g: 0x00,
+                b: 0xaa
+            })
+        );
+        assert_eq!(parse_color_literal("#ff00"), None);
+        assert_eq!(parse_color_literal("#gg0000"), None);
+    }
+
+    #[test]
+    fn test_parse_color_literal_x11_rgb() {
+        assert_eq!(
+            parse_color_literal("rgb:f/a1/a12"),
+            Some(Color::Rgb {
+                r: 0xff,
+                g: 0xa1,
+                b: 0xa1
+            })
+        );
+        assert_eq!(parse_color_literal("rgb:f/a1"), None);
+        assert_eq!(parse_color_literal("rgb:f/a1/a1/a1"), None);
+        assert_eq!(parse_color_literal("not-a-color"), None);
+    }
+
+    #[test]
+    fn test_scale_component_left_justifies() {
+        assert_eq!(scale_component("f"), Some(0xff));
+        assert_eq!(scale_component("a1"), Some(0xa1));
+        assert_eq!(scale_component("a12"), Some(0xa1));
+        assert_eq!(scale_component("a123"), Some(0xa1));
+        assert_eq!(scale_component(""), None);
+        assert_eq!(scale_component("abcde"), None);
+        assert_eq!(scale_component("zz"), None);
+    }
 }